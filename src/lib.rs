@@ -3,8 +3,12 @@
 //! This crate provides newtype wrappers around chrono's [`NaiveDateTime`], [`NaiveDate`],
 //! [`NaiveTime`], and [`Duration`] structs, that can be used in [`PyO3`](pyo3) applications.
 //!
-//! Leap seconds are handled correctly, however timezones are not supported because Python itself
-//! doesn't inherently support timezones in its datetimes.
+//! Leap seconds are handled correctly. Timezone-aware datetimes are supported as well, through
+//! [`DateTime`] (wrapping [`chrono::DateTime<chrono::FixedOffset>`]) and [`Utc`] (wrapping
+//! [`chrono::DateTime<chrono::Utc>`]) - these carry the UTC offset of Python's `tzinfo` across
+//! the conversion, as opposed to the naive types which ignore it entirely. Only the offset is
+//! kept: converting back to Python yields a fixed-offset `datetime.timezone` rather than the
+//! original `tzinfo` object, and [`Utc`] converts any offset to UTC.
 //!
 //! Implementations for the [`serde::Serialize`] and [`serde::Deserialize`] traits can be enabled via the
 //! `serde` feature flag.
@@ -29,7 +33,7 @@ pub use pyo3;
 #[cfg(feature = "serde")]
 pub use serde_ as serde;
 
-use chrono::{Datelike as _, Timelike as _};
+use chrono::{Datelike as _, Offset as _, Timelike as _};
 use pyo3::types::{PyDateAccess as _, PyDeltaAccess as _, PyTimeAccess as _};
 use std::convert::TryInto as _;
 
@@ -49,6 +53,31 @@ fn py_to_micros(time: &impl pyo3::types::PyTimeAccess) -> u32 {
     }
 }
 
+/// Build a `datetime.timezone` instance representing a fixed UTC offset of `offset_secs` seconds,
+/// for use as the `tzinfo` of an aware `datetime.datetime`
+fn offset_secs_to_tzinfo(py: pyo3::Python, offset_secs: i32) -> pyo3::PyResult<pyo3::PyObject> {
+    let datetime_module = py.import("datetime")?;
+    let timedelta = datetime_module
+        .getattr("timedelta")?
+        .call1((0, offset_secs))?;
+    Ok(datetime_module
+        .getattr("timezone")?
+        .call1((timedelta,))?
+        .to_object(py))
+}
+
+/// Read the `utcoffset()` of a Python `datetime.datetime`, in seconds. Returns `None` if the
+/// datetime is naive (`tzinfo` is `None` or `utcoffset()` returns `None`)
+fn py_utcoffset_secs(pydatetime: &pyo3::types::PyDateTime) -> pyo3::PyResult<Option<i64>> {
+    let utcoffset = pydatetime.call_method0("utcoffset")?;
+    if utcoffset.is_none() {
+        Ok(None)
+    } else {
+        let total_seconds: f64 = utcoffset.call_method0("total_seconds")?.extract()?;
+        Ok(Some(total_seconds.round() as i64))
+    }
+}
+
 macro_rules! new_type {
     ($doc:literal, $name:ident, $inner_type:ty) => {
         #[doc = $doc]
@@ -145,6 +174,221 @@ impl pyo3::FromPyObject<'_> for NaiveDateTime {
     }
 }
 
+impl NaiveDateTime {
+    /// Parse a `NaiveDateTime` from `s`, according to the strftime-like `fmt`. See
+    /// [`chrono::format::strftime`] for the list of supported format specifiers
+    pub fn parse_from_str(s: &str, fmt: &str) -> pyo3::PyResult<Self> {
+        chrono::NaiveDateTime::parse_from_str(s, fmt)
+            .map(NaiveDateTime)
+            .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))
+    }
+
+    /// Format this `NaiveDateTime` according to the strftime-like `fmt`. See
+    /// [`chrono::format::strftime`] for the list of supported format specifiers
+    pub fn format(&self, fmt: &str) -> String {
+        self.0.format(fmt).to_string()
+    }
+}
+
+new_type!(
+    "A wrapper around [`chrono::DateTime<chrono::FixedOffset>`] that can be converted to and from \
+     a timezone-aware Python's `datetime.datetime`",
+    DateTime,
+    chrono::DateTime<chrono::FixedOffset>
+);
+impl_serde_traits!(DateTime, chrono::DateTime<chrono::FixedOffset>);
+
+impl pyo3::ToPyObject for DateTime {
+    fn to_object(&self, py: pyo3::Python) -> pyo3::PyObject {
+        let (micros, fold) = chrono_to_micros_and_fold(self.0);
+        let offset_secs = self.0.offset().fix().local_minus_utc();
+        let tzinfo = offset_secs_to_tzinfo(py, offset_secs).unwrap();
+        pyo3::types::PyDateTime::new_with_fold(
+            py,
+            self.0.year(),
+            self.0.month() as u8,
+            self.0.day() as u8,
+            self.0.hour() as u8,
+            self.0.minute() as u8,
+            self.0.second() as u8,
+            micros,
+            Some(&tzinfo),
+            fold,
+        )
+        .unwrap()
+        .to_object(py)
+    }
+}
+
+impl pyo3::IntoPy<pyo3::PyObject> for DateTime {
+    fn into_py(self, py: pyo3::Python) -> pyo3::PyObject {
+        pyo3::ToPyObject::to_object(&self, py)
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DateTime {
+    fn extract(ob: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        let pydatetime: &pyo3::types::PyDateTime = pyo3::PyTryFrom::try_from(ob)?;
+        let naive = chrono::NaiveDate::from_ymd(
+            pydatetime.get_year(),
+            pydatetime.get_month() as u32,
+            pydatetime.get_day() as u32,
+        )
+        .and_hms_micro(
+            pydatetime.get_hour() as u32,
+            pydatetime.get_minute() as u32,
+            pydatetime.get_second() as u32,
+            py_to_micros(pydatetime),
+        );
+
+        let offset_secs = py_utcoffset_secs(pydatetime)?.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "expected a timezone-aware datetime.datetime, got a naive one",
+            )
+        })?;
+        let offset = chrono::FixedOffset::east_opt(offset_secs as i32).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("utcoffset() is out of FixedOffset's bounds")
+        })?;
+
+        naive.and_local_timezone(offset).single().map(DateTime).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "datetime is ambiguous or invalid in its own offset",
+            )
+        })
+    }
+}
+
+impl DateTime {
+    /// Format this `DateTime` as an RFC 3339 string, including its UTC offset
+    pub fn to_rfc3339(&self) -> String {
+        self.0.to_rfc3339()
+    }
+}
+
+new_type!(
+    "A wrapper around [`chrono::DateTime<chrono::Utc>`] that can be converted to and from a \
+     UTC timezone-aware Python's `datetime.datetime`",
+    Utc,
+    chrono::DateTime<chrono::Utc>
+);
+impl_serde_traits!(Utc, chrono::DateTime<chrono::Utc>);
+
+impl pyo3::ToPyObject for Utc {
+    fn to_object(&self, py: pyo3::Python) -> pyo3::PyObject {
+        DateTime(self.0.with_timezone(&chrono::FixedOffset::east(0))).to_object(py)
+    }
+}
+
+impl pyo3::IntoPy<pyo3::PyObject> for Utc {
+    fn into_py(self, py: pyo3::Python) -> pyo3::PyObject {
+        pyo3::ToPyObject::to_object(&self, py)
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Utc {
+    fn extract(ob: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        let DateTime(aware) = DateTime::extract(ob)?;
+        Ok(Utc(aware.with_timezone(&chrono::Utc)))
+    }
+}
+
+// Hand-rolled instead of going through `new_type!`, since unlike the other wrapped types,
+// `std::time::SystemTime` doesn't implement `Display` - only `Debug`.
+/// A wrapper around [`std::time::SystemTime`] that can be converted to and from Python's
+/// UTC-aware `datetime.datetime`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SystemTime(pub std::time::SystemTime);
+
+impl From<std::time::SystemTime> for SystemTime {
+    fn from(inner: std::time::SystemTime) -> Self {
+        Self(inner)
+    }
+}
+
+impl From<SystemTime> for std::time::SystemTime {
+    fn from(wrapper: SystemTime) -> Self {
+        wrapper.0
+    }
+}
+
+impl pyo3::ToPyObject for SystemTime {
+    fn to_object(&self, py: pyo3::Python) -> pyo3::PyObject {
+        // SystemTime has no inherent notion of a calendar, so we go via its duration relative to
+        // the Unix epoch, then build a `chrono::DateTime<Utc>` out of that
+        let (duration, sign) = match self.0.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => (duration, 1),
+            Err(before_epoch) => (before_epoch.duration(), -1),
+        };
+        let secs: i64 = duration.as_secs().try_into().unwrap_or(i64::MAX);
+        let total_micros = sign
+            * secs
+                .saturating_mul(1_000_000)
+                .saturating_add(duration.subsec_micros() as i64);
+
+        let unix_epoch = chrono::NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0);
+        // `NaiveDateTime` addition panics on overflow, and a `SystemTime` far enough from the
+        // epoch can exceed even Chrono's ~+-262,000 year range - so rather than risk that panic,
+        // we saturate to Python's own `MINYEAR`/`MAXYEAR` bounds, which we'd have to fit into
+        // anyway to build a `datetime.datetime` below
+        let python_min = chrono::NaiveDate::from_ymd(1, 1, 1).and_hms(0, 0, 0);
+        let python_max =
+            chrono::NaiveDate::from_ymd(9999, 12, 31).and_hms_micro(23, 59, 59, 999_999);
+        let naive = unix_epoch
+            .checked_add_signed(chrono::Duration::microseconds(total_micros))
+            .unwrap_or(if total_micros >= 0 { python_max } else { python_min })
+            .max(python_min)
+            .min(python_max);
+        Utc(chrono::DateTime::from_utc(naive, chrono::Utc)).to_object(py)
+    }
+}
+
+impl pyo3::IntoPy<pyo3::PyObject> for SystemTime {
+    fn into_py(self, py: pyo3::Python) -> pyo3::PyObject {
+        pyo3::ToPyObject::to_object(&self, py)
+    }
+}
+
+impl pyo3::FromPyObject<'_> for SystemTime {
+    fn extract(ob: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        let pydatetime: &pyo3::types::PyDateTime = pyo3::PyTryFrom::try_from(ob)?;
+
+        // Like `DateTime`/`Utc`, reject naive datetimes instead of letting `.timestamp()` quietly
+        // interpret them in the local system timezone
+        if py_utcoffset_secs(pydatetime)?.is_none() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "expected a timezone-aware datetime.datetime, got a naive one",
+            ));
+        }
+
+        let timestamp: f64 = pydatetime.call_method0("timestamp")?.extract()?;
+
+        let micros = (timestamp.abs() * 1_000_000.0).round();
+        let duration = std::time::Duration::from_micros(if micros.is_finite() {
+            micros as u64
+        } else {
+            u64::MAX
+        });
+
+        // `SystemTime`'s representable range is platform-dependent, so rather than risk a panic
+        // on an out-of-range timestamp, a timestamp that the platform can't represent falls back
+        // to `u32::MAX` seconds (about 136 years) on the same side of the epoch - comfortably
+        // within bounds on every platform chrono/pyo3 support. This fallback is not the platform's
+        // own `SystemTime` limit; timestamps the platform can represent are kept unchanged
+        let fallback_duration = std::time::Duration::from_secs(u32::MAX as u64);
+        let system_time = if timestamp >= 0.0 {
+            std::time::UNIX_EPOCH
+                .checked_add(duration)
+                .unwrap_or(std::time::UNIX_EPOCH + fallback_duration)
+        } else {
+            std::time::UNIX_EPOCH
+                .checked_sub(duration)
+                .unwrap_or(std::time::UNIX_EPOCH - fallback_duration)
+        };
+
+        Ok(SystemTime(system_time))
+    }
+}
+
 new_type!(
     "A wrapper around [`chrono::NaiveDate`] that can be converted to and from Python's `datetime.date`",
     NaiveDate,
@@ -177,6 +421,45 @@ impl pyo3::FromPyObject<'_> for NaiveDate {
     }
 }
 
+impl NaiveDate {
+    /// Like [`FromPyObject::extract`](pyo3::FromPyObject::extract), but returns a
+    /// [`pyo3::exceptions::PyOverflowError`] instead of panicking if `date`'s year is out of chrono's supported range, rather than
+    /// trusting that every Python `datetime.date` (whose year is always between `MINYEAR` and
+    /// `MAXYEAR`) is representable.
+    ///
+    /// Unlike [`Duration::try_from_pydelta`]'s overflow case, this one is unreachable in
+    /// practice: `datetime.date`'s year is always within `1..=9999`, which is always well inside
+    /// chrono's much wider `NaiveDate` range. It's kept as a guard (and to mirror that API)
+    /// rather than because any real `PyDate` can trigger it.
+    pub fn try_from_pydate(date: &pyo3::types::PyDate) -> pyo3::PyResult<Self> {
+        chrono::NaiveDate::from_ymd_opt(
+            date.get_year(),
+            date.get_month() as u32,
+            date.get_day() as u32,
+        )
+        .map(NaiveDate)
+        .ok_or_else(|| {
+            pyo3::exceptions::PyOverflowError::new_err(
+                "date is out of range for chrono::NaiveDate",
+            )
+        })
+    }
+
+    /// Parse a `NaiveDate` from `s`, according to the strftime-like `fmt`. See
+    /// [`chrono::format::strftime`] for the list of supported format specifiers
+    pub fn parse_from_str(s: &str, fmt: &str) -> pyo3::PyResult<Self> {
+        chrono::NaiveDate::parse_from_str(s, fmt)
+            .map(NaiveDate)
+            .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))
+    }
+
+    /// Format this `NaiveDate` according to the strftime-like `fmt`. See
+    /// [`chrono::format::strftime`] for the list of supported format specifiers
+    pub fn format(&self, fmt: &str) -> String {
+        self.0.format(fmt).to_string()
+    }
+}
+
 new_type!(
     "A wrapper around [`chrono::NaiveTime`] that can be converted to and from Python's `datetime.time`",
     NaiveTime,
@@ -219,12 +502,44 @@ impl pyo3::FromPyObject<'_> for NaiveTime {
     }
 }
 
+impl NaiveTime {
+    /// Parse a `NaiveTime` from `s`, according to the strftime-like `fmt`. See
+    /// [`chrono::format::strftime`] for the list of supported format specifiers
+    pub fn parse_from_str(s: &str, fmt: &str) -> pyo3::PyResult<Self> {
+        chrono::NaiveTime::parse_from_str(s, fmt)
+            .map(NaiveTime)
+            .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))
+    }
+
+    /// Format this `NaiveTime` according to the strftime-like `fmt`. See
+    /// [`chrono::format::strftime`] for the list of supported format specifiers
+    pub fn format(&self, fmt: &str) -> String {
+        self.0.format(fmt).to_string()
+    }
+}
+
 new_type!(
     "A wrapper around [`chrono::Duration`] that can be converted to and from Python's `datetime.timedelta`",
     Duration,
     chrono::Duration
 );
-// impl_serde_traits!(Duration, chrono::Duration); // chrono doesn't yet support serde traits for it
+// chrono doesn't derive serde for `Duration`, so we hand-roll it here, encoding as the total
+// number of microseconds - the same representation used by the `ToPyObject`/`FromPyObject` impls
+// below, including their saturating-truncation behavior for out-of-range values
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Duration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.num_microseconds().unwrap_or(i64::MAX).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(|micros| Duration(chrono::Duration::microseconds(micros)))
+    }
+}
 
 impl pyo3::ToPyObject for Duration {
     fn to_object(&self, py: pyo3::Python) -> pyo3::PyObject {
@@ -269,6 +584,51 @@ impl pyo3::FromPyObject<'_> for Duration {
     }
 }
 
+impl Duration {
+    /// Like [`FromPyObject::extract`](pyo3::FromPyObject::extract), but returns a
+    /// [`pyo3::exceptions::PyOverflowError`] instead of silently clamping when `delta` can't be represented exactly as a
+    /// [`chrono::Duration`] (see the [crate-level docs](crate#truncation) for background)
+    pub fn try_from_pydelta(delta: &pyo3::types::PyDelta) -> pyo3::PyResult<Self> {
+        let overflow_err = || {
+            pyo3::exceptions::PyOverflowError::new_err(
+                "timedelta is out of range for chrono::Duration",
+            )
+        };
+
+        let total_days = delta.get_days() as i64;
+        let total_seconds = total_days * 24 * 60 * 60 + delta.get_seconds() as i64;
+        let total_microseconds = total_seconds
+            .checked_mul(1_000_000)
+            .and_then(|micros| micros.checked_add(delta.get_microseconds() as i64))
+            .ok_or_else(overflow_err)?;
+
+        Ok(Duration(chrono::Duration::microseconds(total_microseconds)))
+    }
+
+    /// Like [`ToPyObject::to_object`](pyo3::ToPyObject::to_object), but returns a
+    /// [`pyo3::exceptions::PyOverflowError`] instead of silently clamping when `self`'s total
+    /// number of microseconds doesn't fit in an `i64` (the case in which `to_object` clamps). Any
+    /// sub-microsecond part of `self` is dropped without an error
+    pub fn try_to_object(&self, py: pyo3::Python) -> pyo3::PyResult<pyo3::PyObject> {
+        const MICROSECONDS_PER_DAY: i64 = 60 * 60 * 24 * 1_000_000;
+
+        let overflow_err = || {
+            pyo3::exceptions::PyOverflowError::new_err(
+                "Duration's total microseconds don't fit in chrono's i64, can't build an exact \
+                 timedelta",
+            )
+        };
+
+        let total_micros = self.0.num_microseconds().ok_or_else(overflow_err)?;
+        let total_days: i32 = (total_micros / MICROSECONDS_PER_DAY)
+            .try_into()
+            .map_err(|_| overflow_err())?;
+        let subday_micros = (total_micros % MICROSECONDS_PER_DAY) as i32;
+
+        Ok(pyo3::types::PyDelta::new(py, total_days, 0, subday_micros, true)?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,6 +715,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_datetime_aware() {
+        let py = pyo3::Python::acquire_gil();
+        let py = py.python();
+
+        for &(year, month, day, hour, min, sec, micro, offset_secs) in &[
+            (2021, 1, 20, 22, 39, 46, 186605, 0),     // UTC
+            (2021, 1, 20, 22, 39, 46, 186605, 3600),  // positive offset
+            (2021, 1, 20, 22, 39, 46, 186605, -18000), // negative offset
+            (2021, 1, 20, 22, 39, 46, 186605, 1800),  // non-whole-hour offset
+        ] {
+            let offset = chrono::FixedOffset::east(offset_secs);
+            let tzinfo = offset_secs_to_tzinfo(py, offset_secs).unwrap();
+
+            let py_datetime = pyo3::types::PyDateTime::new_with_fold(
+                py,
+                year,
+                month,
+                day,
+                hour,
+                min,
+                sec,
+                micro,
+                Some(&tzinfo),
+                false,
+            )
+            .unwrap();
+            let naive = chrono::NaiveDate::from_ymd(year, month.into(), day.into()).and_hms_micro(
+                hour.into(),
+                min.into(),
+                sec.into(),
+                micro,
+            );
+            let chrono_datetime = DateTime(naive.and_local_timezone(offset).unwrap());
+
+            assert_eq!(
+                py_datetime.extract::<DateTime>().unwrap(),
+                chrono_datetime
+            );
+            assert_py_eq(py_datetime, &chrono_datetime.to_object(py));
+            assert_eq!(
+                chrono_datetime.to_rfc3339(),
+                chrono_datetime.0.to_rfc3339()
+            );
+
+            if offset_secs == 0 {
+                let chrono_utc = Utc(chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc));
+                assert_eq!(py_datetime.extract::<Utc>().unwrap(), chrono_utc);
+                assert_py_eq(py_datetime, &chrono_utc.to_object(py));
+            }
+        }
+
+        // A naive datetime should fail to extract as either aware type
+        let py_naive = pyo3::types::PyDateTime::new(py, 2021, 1, 20, 22, 39, 46, 186605, None)
+            .unwrap();
+        assert!(py_naive.extract::<DateTime>().is_err());
+        assert!(py_naive.extract::<Utc>().is_err());
+    }
+
+    #[test]
+    fn test_system_time() {
+        let py = pyo3::Python::acquire_gil();
+        let py = py.python();
+
+        for &secs_since_epoch in &[0i64, 1, -1, 1_611_182_386, -1_611_182_386] {
+            let system_time = if secs_since_epoch >= 0 {
+                std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs_since_epoch as u64)
+            } else {
+                std::time::UNIX_EPOCH - std::time::Duration::from_secs((-secs_since_epoch) as u64)
+            };
+
+            let roundtripped = SystemTime(system_time)
+                .to_object(py)
+                .as_ref(py)
+                .extract::<SystemTime>()
+                .unwrap();
+            assert_eq!(roundtripped.0, system_time);
+        }
+    }
+
     #[test]
     fn test_duration() {
         let py = pyo3::Python::acquire_gil();
@@ -405,4 +845,75 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_duration_fallible() {
+        let py = pyo3::Python::acquire_gil();
+        let py = py.python();
+
+        // Values well within Chrono's range should round-trip exactly through the fallible APIs
+        let py_duration = pyo3::types::PyDelta::new(py, 5, 4, 3, true).unwrap();
+        let duration = Duration::try_from_pydelta(py_duration).unwrap();
+        assert_eq!(duration, Duration(chrono::Duration::microseconds(432_004_000_003)));
+        assert_py_eq(py_duration, &duration.try_to_object(py).unwrap());
+
+        // Python's true minimum and maximum timedeltas (+-1e9 days) are out of Chrono's range
+        let py_max_duration = pyo3::types::PyDelta::new(py, 999_999_999, 86399, 999_999, true).unwrap();
+        assert!(Duration::try_from_pydelta(py_max_duration).is_err());
+
+        let py_min_duration = pyo3::types::PyDelta::new(py, -999_999_999, 0, 0, true).unwrap();
+        assert!(Duration::try_from_pydelta(py_min_duration).is_err());
+
+        // A chrono::Duration that doesn't fit in i64 microseconds can't be turned into an exact
+        // timedelta either
+        assert!(Duration(chrono::Duration::max_value()).try_to_object(py).is_err());
+    }
+
+    #[test]
+    fn test_naive_date_fallible() {
+        let py = pyo3::Python::acquire_gil();
+        let py = py.python();
+
+        // Python's MINYEAR/MAXYEAR are both well within Chrono's supported range, so there's no
+        // `PyDate` that exercises the `PyOverflowError` branch - only the success path is
+        // checked here
+        let py_min_date = pyo3::types::PyDate::new(py, 1, 1, 1).unwrap();
+        let py_max_date = pyo3::types::PyDate::new(py, 9999, 12, 31).unwrap();
+
+        assert_eq!(
+            NaiveDate::try_from_pydate(py_min_date).unwrap(),
+            NaiveDate(chrono::NaiveDate::from_ymd(1, 1, 1))
+        );
+        assert_eq!(
+            NaiveDate::try_from_pydate(py_max_date).unwrap(),
+            NaiveDate(chrono::NaiveDate::from_ymd(9999, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_strftime_helpers() {
+        let datetime = NaiveDateTime(
+            chrono::NaiveDate::from_ymd(2021, 1, 20).and_hms_micro(22, 39, 46, 186605),
+        );
+        assert_eq!(datetime.format("%Y-%m-%d %H:%M:%S"), "2021-01-20 22:39:46");
+        assert_eq!(
+            NaiveDateTime::parse_from_str("2021-01-20 22:39:46", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime(chrono::NaiveDate::from_ymd(2021, 1, 20).and_hms(22, 39, 46))
+        );
+        assert!(NaiveDateTime::parse_from_str("not a datetime", "%Y-%m-%d %H:%M:%S").is_err());
+
+        let date = NaiveDate(chrono::NaiveDate::from_ymd(2021, 1, 20));
+        assert_eq!(date.format("%Y-%m-%d"), "2021-01-20");
+        assert_eq!(
+            NaiveDate::parse_from_str("2021-01-20", "%Y-%m-%d").unwrap(),
+            date
+        );
+
+        let time = NaiveTime(chrono::NaiveTime::from_hms_micro(22, 39, 46, 186605));
+        assert_eq!(time.format("%H:%M:%S"), "22:39:46");
+        assert_eq!(
+            NaiveTime::parse_from_str("22:39:46", "%H:%M:%S").unwrap(),
+            NaiveTime(chrono::NaiveTime::from_hms(22, 39, 46))
+        );
+    }
 }